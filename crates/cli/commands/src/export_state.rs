@@ -0,0 +1,212 @@
+//! Command that exports a state dump consumable by `init-state`.
+
+use crate::common::{AccessRights, Environment, EnvironmentArgs};
+use clap::Parser;
+use reth_chainspec::ChainSpec;
+use reth_cli::chainspec::ChainSpecParser;
+use reth_db::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    tables,
+    transaction::DbTx,
+};
+use reth_node_builder::{NodeTypesWithDB, NodeTypesWithEngine};
+use reth_primitives::{Address, BlockNumber, B256};
+use reth_provider::{BlockNumReader, HeaderProvider, ProviderFactory};
+use serde::Serialize;
+
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+};
+use tracing::info;
+
+/// Exports a JSONL state dump of the plain state, in the exact format consumed by `init-state`.
+#[derive(Debug, Parser)]
+pub struct ExportStateCommand<C: ChainSpecParser> {
+    #[command(flatten)]
+    env: EnvironmentArgs<C>,
+
+    /// Block number to export the state at. Defaults to the current tip.
+    ///
+    /// The plain-state tables only ever hold the state of the current tip, so exporting at any
+    /// other block number is not supported.
+    #[arg(long, value_name = "BLOCK_NUMBER")]
+    at_block: Option<BlockNumber>,
+
+    /// Output JSONL file.
+    ///
+    /// Will contain { "root": \<state-root\> } as the first line, followed by one JSON object
+    /// per account, in the same schema `init-state` reads:
+    /// {
+    ///     "balance": "\<balance\>",
+    ///     "nonce": \<nonce\>,
+    ///     "code": "\<bytecode\>",
+    ///     "storage": {
+    ///         "\<key\>": "\<value\>",
+    ///         ..
+    ///     },
+    ///     "address": "\<address\>",
+    /// }
+    #[arg(value_name = "OUTPUT_FILE", verbatim_doc_comment)]
+    output: PathBuf,
+}
+
+impl<C: ChainSpecParser<ChainSpec = ChainSpec>> ExportStateCommand<C> {
+    /// Execute the `export-state` command
+    pub async fn execute<N: NodeTypesWithEngine<ChainSpec = C::ChainSpec>>(
+        self,
+    ) -> eyre::Result<()> {
+        info!(target: "reth::cli", "Reth export-state starting");
+
+        let Environment { provider_factory, .. } = self.env.init::<N>(AccessRights::RO)?;
+
+        info!(target: "reth::cli", "Exporting state dump");
+
+        let state_root = export_state_at_block(self.at_block, provider_factory, self.output)?;
+
+        info!(target: "reth::cli", state_root = ?state_root, "State dump written");
+        Ok(())
+    }
+}
+
+/// One line of the JSONL state dump, matching the schema
+/// [`init_from_state_dump`](reth_db_common::init::init_from_state_dump) reads.
+#[derive(Debug, Serialize)]
+struct DumpAccount {
+    balance: String,
+    nonce: u64,
+    code: Option<String>,
+    storage: BTreeMap<B256, B256>,
+    address: Address,
+}
+
+/// Walks the plain-state tables at `at_block` (defaulting to the current tip) and streams them
+/// to `output_path` in the JSONL schema `init_at_state` expects, with the declared state root
+/// leading as the first line.
+///
+/// Only the current tip can be exported: any other `at_block` returns an error before the output
+/// file is created.
+pub fn export_state_at_block<N: NodeTypesWithDB<ChainSpec = ChainSpec>>(
+    at_block: Option<BlockNumber>,
+    factory: ProviderFactory<N>,
+    output_path: PathBuf,
+) -> eyre::Result<B256> {
+    let provider = factory.provider()?;
+
+    let tip = provider.best_block_number()?;
+    let block_number = at_block.unwrap_or(tip);
+    if block_number != tip {
+        eyre::bail!(
+            "cannot export state at block {block_number}: the plain-state tables only hold the \
+             state of the current tip ({tip})"
+        );
+    }
+
+    let header = provider
+        .header_by_number(block_number)?
+        .ok_or_else(|| eyre::eyre!("missing header for block {block_number}"))?;
+
+    let file = File::create(&output_path)?;
+    let mut writer = BufWriter::new(file);
+
+    writeln!(writer, "{}", serde_json::json!({ "root": header.state_root }))?;
+
+    let tx = provider.tx_ref();
+    let mut accounts_cursor = tx.cursor_read::<tables::PlainAccountState>()?;
+    let mut storage_cursor = tx.cursor_dup_read::<tables::PlainStorageState>()?;
+    let mut bytecode_cursor = tx.cursor_read::<tables::Bytecodes>()?;
+
+    let mut accounts_walker = accounts_cursor.walk(None)?;
+    while let Some((address, account)) = accounts_walker.next().transpose()? {
+        let storage = storage_cursor
+            .walk_dup(Some(address), None)?
+            .map(|entry| entry.map(|(_, entry)| (entry.key, entry.value.into())))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+        let code = account
+            .bytecode_hash
+            .map(|hash| {
+                bytecode_cursor
+                    .seek_exact(hash)?
+                    .map(|(_, bytecode)| alloy_primitives::hex::encode_prefixed(bytecode.bytes()))
+                    .ok_or_else(|| eyre::eyre!("missing bytecode for hash {hash}"))
+            })
+            .transpose()?;
+
+        let dump_account = DumpAccount {
+            balance: account.balance.to_string(),
+            nonce: account.nonce,
+            code,
+            storage,
+            address,
+        };
+
+        writeln!(writer, "{}", serde_json::to_string(&dump_account)?)?;
+    }
+
+    writer.flush()?;
+
+    Ok(header.state_root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_db::{models::StorageEntry, transaction::DbTxMut};
+    use reth_primitives::{Account, Header, U256};
+    use reth_provider::test_utils::create_test_provider_factory;
+
+    #[test]
+    fn exports_plain_state_at_tip() {
+        let factory = create_test_provider_factory();
+        let address = Address::with_last_byte(1);
+
+        let header = Header { number: 0, state_root: B256::with_last_byte(9), ..Default::default() };
+        let block_hash = header.hash_slow();
+
+        let provider_rw = factory.provider_rw().unwrap();
+        let tx = provider_rw.tx_ref();
+        tx.put::<tables::CanonicalHeaders>(0, block_hash).unwrap();
+        tx.put::<tables::HeaderNumbers>(block_hash, 0).unwrap();
+        tx.put::<tables::Headers>(0, header.clone()).unwrap();
+        tx.put::<tables::PlainAccountState>(
+            address,
+            Account { nonce: 7, balance: U256::from(100), bytecode_hash: None },
+        )
+        .unwrap();
+        tx.put::<tables::PlainStorageState>(
+            address,
+            StorageEntry { key: B256::with_last_byte(1), value: U256::from(42) },
+        )
+        .unwrap();
+        provider_rw.commit().unwrap();
+
+        let output_path = std::env::temp_dir().join("reth-export-state-test-tip.jsonl");
+        let state_root = export_state_at_block(None, factory, output_path.clone()).unwrap();
+        assert_eq!(state_root, header.state_root);
+
+        let contents = std::fs::read_to_string(&output_path).unwrap();
+        std::fs::remove_file(&output_path).ok();
+
+        let mut lines = contents.lines();
+        let root_line: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(root_line["root"], format!("{}", header.state_root));
+
+        let account_line: serde_json::Value = serde_json::from_str(lines.next().unwrap()).unwrap();
+        assert_eq!(account_line["nonce"], 7);
+        assert_eq!(account_line["address"], format!("{address}"));
+    }
+
+    #[test]
+    fn rejects_non_tip_block() {
+        let factory = create_test_provider_factory();
+        let output_path = std::env::temp_dir().join("reth-export-state-test-non-tip.jsonl");
+
+        let result = export_state_at_block(Some(1), factory, output_path.clone());
+
+        std::fs::remove_file(&output_path).ok();
+        assert!(result.is_err(), "plain-state tables only hold the tip's state");
+    }
+}
@@ -9,8 +9,14 @@ use reth_db_common::init::init_from_state_dump;
 use reth_node_builder::{NodeTypesWithDB, NodeTypesWithEngine};
 use reth_primitives::B256;
 use reth_provider::ProviderFactory;
+use reth_trie::StateRoot;
+use serde::Deserialize;
 
-use std::{fs::File, io::BufReader, path::PathBuf};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
 use tracing::info;
 
 /// Initializes the database with the genesis block.
@@ -38,6 +44,46 @@ pub struct InitStateCommand<C: ChainSpecParser> {
     /// and including the non-genesis block to init chain at. See 'import' command.
     #[arg(value_name = "STATE_DUMP_FILE", verbatim_doc_comment)]
     state: PathBuf,
+
+    /// Compression the state dump file was written with.
+    ///
+    /// By default this is detected from the `STATE_DUMP_FILE` extension (`.gz`, `.zst`),
+    /// falling back to uncompressed.
+    #[arg(long, value_enum)]
+    compression: Option<StateDumpCompression>,
+}
+
+/// Compression algorithm a state dump file may be written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum StateDumpCompression {
+    /// Uncompressed JSONL.
+    None,
+    /// Gzip-compressed (`.gz`).
+    Gzip,
+    /// Zstd-compressed (`.zst`).
+    Zstd,
+}
+
+impl StateDumpCompression {
+    /// Detects the compression of a state dump file from its extension, defaulting to
+    /// [`Self::None`] if it's not recognized.
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gz") => Self::Gzip,
+            Some("zst") => Self::Zstd,
+            _ => Self::None,
+        }
+    }
+
+    /// Wraps `file` in the streaming decoder for this compression, so the decompressed dump is
+    /// never fully materialized on disk or in memory.
+    fn reader(self, file: File) -> eyre::Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Self::None => Box::new(BufReader::new(file)),
+            Self::Gzip => Box::new(BufReader::new(flate2::read::GzDecoder::new(file))),
+            Self::Zstd => Box::new(BufReader::new(zstd::stream::read::Decoder::new(file)?)),
+        })
+    }
 }
 
 impl<C: ChainSpecParser<ChainSpec = ChainSpec>> InitStateCommand<C> {
@@ -51,7 +97,8 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> InitStateCommand<C> {
 
         info!(target: "reth::cli", "Initiating state dump");
 
-        let hash = init_at_state(self.state, provider_factory, config.stages.etl)?;
+        let hash =
+            init_at_state(self.state, self.compression, provider_factory, config.stages.etl)?;
 
         info!(target: "reth::cli", hash = ?hash, "Genesis block written");
         Ok(())
@@ -59,8 +106,13 @@ impl<C: ChainSpecParser<ChainSpec = ChainSpec>> InitStateCommand<C> {
 }
 
 /// Initialize chain with state at specific block, from a file with state dump.
+///
+/// After loading, recomputes the state root from the now-populated hashed-state and trie tables
+/// and hard-fails if it doesn't match the `root` declared in the dump, so a truncated or
+/// corrupted dump is caught instead of being silently trusted.
 pub fn init_at_state<N: NodeTypesWithDB<ChainSpec = ChainSpec>>(
     state_dump_path: PathBuf,
+    compression: Option<StateDumpCompression>,
     factory: ProviderFactory<N>,
     etl_config: EtlConfig,
 ) -> eyre::Result<B256> {
@@ -68,8 +120,82 @@ pub fn init_at_state<N: NodeTypesWithDB<ChainSpec = ChainSpec>>(
         path=?state_dump_path,
         "Opening state dump");
 
-    let file = File::open(state_dump_path)?;
-    let reader = BufReader::new(file);
+    let compression =
+        compression.unwrap_or_else(|| StateDumpCompression::from_path(&state_dump_path));
+
+    let expected_state_root = read_expected_state_root(&state_dump_path, compression)?;
+
+    let reader = compression.reader(File::open(&state_dump_path)?)?;
+
+    let hash = init_from_state_dump(reader, factory.clone(), etl_config)?;
+
+    info!(target: "reth::cli", "Verifying state root after loading state dump");
+
+    let provider = factory.provider()?;
+    let computed_state_root = StateRoot::from_tx(provider.tx_ref())
+        .root()
+        .map_err(|err| eyre::eyre!("failed to compute state root from loaded state dump: {err}"))?;
+
+    if computed_state_root != expected_state_root {
+        eyre::bail!(
+            "state root mismatch after loading state dump: dump declared {expected_state_root}, \
+             but the loaded state's root is {computed_state_root}",
+        );
+    }
+
+    Ok(hash)
+}
+
+/// The `{ "root": <state-root> }` header line of a state dump file.
+#[derive(Deserialize)]
+struct StateDumpRoot {
+    root: B256,
+}
+
+/// Reads and parses the leading `{ "root": <state-root> }` line of a state dump file, without
+/// consuming the rest of it.
+fn read_expected_state_root(
+    state_dump_path: &Path,
+    compression: StateDumpCompression,
+) -> eyre::Result<B256> {
+    let mut reader = compression.reader(File::open(state_dump_path)?)?;
+    let mut first_line = String::new();
+    reader.read_line(&mut first_line)?;
 
-    init_from_state_dump(reader, factory, etl_config)
+    let StateDumpRoot { root } = serde_json::from_str(&first_line)?;
+    Ok(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_provider::test_utils::create_test_provider_factory;
+    use std::io::Write as _;
+
+    #[test]
+    fn mismatched_declared_root_is_rejected() {
+        let factory = create_test_provider_factory();
+
+        let dump_path = std::env::temp_dir().join("reth-init-state-test-mismatched-root.jsonl");
+        let mut file = File::create(&dump_path).unwrap();
+        writeln!(
+            file,
+            r#"{{"root":"0x0000000000000000000000000000000000000000000000000000000000000001"}}"#
+        )
+        .unwrap();
+        writeln!(
+            file,
+            r#"{{"balance":"0x64","nonce":0,"code":null,"storage":{{}},"address":"0x0000000000000000000000000000000000000001"}}"#
+        )
+        .unwrap();
+
+        let result = init_at_state(dump_path.clone(), None, factory, EtlConfig::default());
+
+        std::fs::remove_file(&dump_path).ok();
+
+        assert!(
+            result.is_err(),
+            "dump declares a root that can't match the trie root of the account it loads"
+        );
+    }
 }
@@ -0,0 +1,5 @@
+//! Standalone commands that are composed into the node's CLI.
+
+pub mod common;
+pub mod export_state;
+pub mod init_state;
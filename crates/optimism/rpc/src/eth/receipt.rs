@@ -1,24 +1,24 @@
 //! Loads and formats OP receipt RPC response.
 
 use op_alloy_rpc_types::{receipt::L1BlockInfo, OptimismTransactionReceiptFields};
-use reth_chainspec::{ChainSpec, OptimismHardforks};
+use reth_chainspec::OptimismHardforks;
 use reth_evm_optimism::RethL1BlockInfo;
 use reth_node_api::{FullNodeComponents, NodeTypes};
-use reth_primitives::{Receipt, TransactionMeta, TransactionSigned};
-use reth_provider::ChainSpecProvider;
+use reth_primitives::{Receipt, TransactionMeta, TransactionSigned, B256};
+use reth_provider::{BlockIdReader, ChainSpecProvider};
 use reth_rpc_eth_api::{
-    helpers::{EthApiSpec, LoadReceipt, LoadTransaction},
+    helpers::{EthApiSpec, EthBlocks, LoadBlock, LoadReceipt, LoadTransaction},
     FromEthApiError,
 };
 use reth_rpc_eth_types::{EthApiError, EthStateCache, ReceiptBuilder};
-use reth_rpc_types::AnyTransactionReceipt;
+use reth_rpc_types::{AnyTransactionReceipt, BlockId};
 
 use crate::{OpEthApi, OpEthApiError};
 
 impl<N> LoadReceipt for OpEthApi<N>
 where
     Self: EthApiSpec + LoadTransaction<Error = OpEthApiError>,
-    N: FullNodeComponents<Types: NodeTypes<ChainSpec = ChainSpec>>,
+    N: FullNodeComponents<Types: NodeTypes<ChainSpec: OptimismHardforks>>,
 {
     #[inline]
     fn cache(&self) -> &EthStateCache {
@@ -41,35 +41,135 @@ where
         let l1_block_info =
             reth_evm_optimism::extract_l1_info(&block).map_err(OpEthApiError::from)?;
 
-        let op_receipt_meta = self
-            .build_op_receipt_meta(&tx, l1_block_info, &receipt)
+        let (op_receipt_meta, effective_gas_price) = self
+            .build_op_receipt_meta(
+                &tx,
+                l1_block_info,
+                block.timestamp,
+                block.base_fee_per_gas,
+                &receipt,
+            )
             .map_err(OpEthApiError::from)?;
 
-        let receipt_resp = ReceiptBuilder::new(&tx, meta, &receipt, &receipts)
+        let mut receipt_resp = ReceiptBuilder::new(&tx, meta, &receipt, &receipts)
             .map_err(Self::Error::from_eth_err)?
             .add_other_fields(op_receipt_meta.into())
             .build();
+        receipt_resp.effective_gas_price = effective_gas_price;
 
         Ok(receipt_resp)
     }
 }
 
+impl<N> EthBlocks for OpEthApi<N>
+where
+    Self: LoadBlock + LoadReceipt,
+    N: FullNodeComponents<Types: NodeTypes<ChainSpec: OptimismHardforks>>,
+{
+    /// Overrides the default [`EthBlocks::block_receipts`], which loops
+    /// [`build_transaction_receipt`](LoadReceipt::build_transaction_receipt) once per transaction,
+    /// with [`build_block_receipts`](OpEthApi::build_block_receipts), which fetches the block and
+    /// extracts its L1 info only once for the whole block.
+    async fn block_receipts(
+        &self,
+        block_id: BlockId,
+    ) -> Result<Option<Vec<AnyTransactionReceipt>>, Self::Error> {
+        let Some(block_hash) = self
+            .inner
+            .provider()
+            .block_hash_for_id(block_id)
+            .map_err(Self::Error::from_eth_err)?
+        else {
+            return Ok(None);
+        };
+
+        self.build_block_receipts(block_hash).await.map(Some).map_err(Self::Error::from)
+    }
+}
+
 impl<N> OpEthApi<N>
 where
-    N: FullNodeComponents<Types: NodeTypes<ChainSpec = ChainSpec>>,
+    N: FullNodeComponents<Types: NodeTypes<ChainSpec: OptimismHardforks>>,
 {
-    /// Builds a receipt w.r.t. chain spec.
+    /// Builds a receipt w.r.t. chain spec, along with the transaction's `effectiveGasPrice`.
     pub fn build_op_receipt_meta(
         &self,
         tx: &TransactionSigned,
         l1_block_info: revm::L1BlockInfo,
+        block_timestamp: u64,
+        base_fee: Option<u64>,
         receipt: &Receipt,
-    ) -> Result<OptimismTransactionReceiptFields, OpEthApiError> {
-        Ok(OpReceiptFieldsBuilder::default()
+    ) -> Result<(OptimismTransactionReceiptFields, u128), OpEthApiError> {
+        let builder = OpReceiptFieldsBuilder::new(block_timestamp)
             .l1_block_info(&self.inner.provider().chain_spec(), tx, l1_block_info)?
             .deposit_nonce(receipt.deposit_nonce)
             .deposit_version(receipt.deposit_receipt_version)
-            .build())
+            .effective_gas_price(tx, base_fee);
+
+        let effective_gas_price = builder.effective_gas_price;
+        Ok((builder.build(), effective_gas_price))
+    }
+
+    /// Builds receipts for every transaction in a block in one pass.
+    ///
+    /// Unlike repeatedly calling
+    /// [`build_transaction_receipt`](LoadReceipt::build_transaction_receipt), this fetches the
+    /// block and its receipts once and calls [`extract_l1_info`](reth_evm_optimism::extract_l1_info)
+    /// once, reusing the resulting [`revm::L1BlockInfo`] for every transaction in the block. This
+    /// backs the `eth_getBlockReceipts` RPC method, which would otherwise re-unseal the block and
+    /// re-parse its L1-attributes deposit transaction once per transaction it contains.
+    pub async fn build_block_receipts(
+        &self,
+        block_hash: B256,
+    ) -> Result<Vec<AnyTransactionReceipt>, OpEthApiError>
+    where
+        Self: LoadReceipt,
+    {
+        let (block, receipts) = LoadReceipt::cache(self)
+            .get_block_and_receipts(block_hash)
+            .await
+            .map_err(OpEthApiError::from_eth_err)?
+            .ok_or(OpEthApiError::from_eth_err(EthApiError::UnknownBlockNumber))?;
+
+        let block = block.unseal();
+        let l1_block_info =
+            reth_evm_optimism::extract_l1_info(&block).map_err(OpEthApiError::from)?;
+
+        let block_number = block.number;
+        let base_fee = block.base_fee_per_gas;
+        let excess_blob_gas = block.excess_blob_gas;
+        let timestamp = block.timestamp;
+
+        block
+            .body
+            .iter()
+            .zip(receipts.iter())
+            .enumerate()
+            .map(|(idx, (tx, receipt))| {
+                let meta = TransactionMeta {
+                    tx_hash: tx.hash(),
+                    index: idx as u64,
+                    block_hash,
+                    block_number,
+                    base_fee,
+                    excess_blob_gas,
+                    timestamp,
+                };
+
+                let (op_receipt_meta, effective_gas_price) = self
+                    .build_op_receipt_meta(tx, l1_block_info.clone(), timestamp, base_fee, receipt)
+                    .map_err(OpEthApiError::from)?;
+
+                ReceiptBuilder::new(tx, meta, receipt, &receipts)
+                    .map_err(OpEthApiError::from_eth_err)
+                    .map(|builder| {
+                        let mut receipt_resp =
+                            builder.add_other_fields(op_receipt_meta.into()).build();
+                        receipt_resp.effective_gas_price = effective_gas_price;
+                        receipt_resp
+                    })
+            })
+            .collect()
     }
 }
 
@@ -101,6 +201,9 @@ pub struct OpReceiptFieldsBuilder {
     pub l1_blob_base_fee: Option<u128>,
     /// The current L1 blob base fee scalar.
     pub l1_blob_base_fee_scalar: Option<u128>,
+    /// The gas price actually paid by the transaction on L2, i.e. `effectiveGasPrice`. Deposit
+    /// transactions pay no L1/L2 execution gas price and always report `0` here.
+    pub effective_gas_price: u128,
 }
 
 impl OpReceiptFieldsBuilder {
@@ -112,13 +215,26 @@ impl OpReceiptFieldsBuilder {
     /// Applies [`L1BlockInfo`](revm::L1BlockInfo).
     pub fn l1_block_info(
         mut self,
-        chain_spec: &ChainSpec,
+        chain_spec: &impl OptimismHardforks,
         tx: &TransactionSigned,
         l1_block_info: revm::L1BlockInfo,
     ) -> Result<Self, OpEthApiError> {
-        let raw_tx = tx.envelope_encoded();
         let timestamp = self.l1_block_timestamp;
 
+        self.l1_base_fee = Some(l1_block_info.l1_base_fee.saturating_to());
+        self.l1_base_fee_scalar = Some(l1_block_info.l1_base_fee_scalar.saturating_to());
+        self.l1_blob_base_fee = l1_block_info.l1_blob_base_fee.map(|fee| fee.saturating_to());
+        self.l1_blob_base_fee_scalar =
+            l1_block_info.l1_blob_base_fee_scalar.map(|scalar| scalar.saturating_to());
+
+        if tx.is_deposit() {
+            // Deposit transactions don't pay the L1 data fee, so there's nothing to attribute
+            // `l1Fee`/`l1GasUsed`/`l1FeeScalar` to.
+            return Ok(self);
+        }
+
+        let raw_tx = tx.envelope_encoded();
+
         self.l1_fee = Some(
             l1_block_info
                 .l1_tx_data_fee(chain_spec, timestamp, &raw_tx, tx.is_deposit())
@@ -134,18 +250,23 @@ impl OpReceiptFieldsBuilder {
                 .saturating_to(),
         );
 
-        self.l1_fee_scalar = (!chain_spec.hardforks.is_ecotone_active_at_timestamp(timestamp))
+        self.l1_fee_scalar = (!chain_spec.is_ecotone_active_at_timestamp(timestamp))
             .then_some(f64::from(l1_block_info.l1_base_fee_scalar) / 1_000_000.0);
 
-        self.l1_base_fee = Some(l1_block_info.l1_base_fee.saturating_to());
-        self.l1_base_fee_scalar = Some(l1_block_info.l1_base_fee_scalar.saturating_to());
-        self.l1_blob_base_fee = l1_block_info.l1_blob_base_fee.map(|fee| fee.saturating_to());
-        self.l1_blob_base_fee_scalar =
-            l1_block_info.l1_blob_base_fee_scalar.map(|scalar| scalar.saturating_to());
-
         Ok(self)
     }
 
+    /// Applies the transaction's effective gas price, i.e. `effectiveGasPrice`.
+    ///
+    /// Deposit transactions pay no L1/L2 execution gas price and always report `0`. Non-deposit
+    /// post-London transactions report the burned base fee plus the effective priority tip, i.e.
+    /// `min(max_fee_per_gas, base_fee + max_priority_fee_per_gas)`, same as for non-OP receipts.
+    pub fn effective_gas_price(mut self, tx: &TransactionSigned, base_fee: Option<u64>) -> Self {
+        self.effective_gas_price =
+            if tx.is_deposit() { 0 } else { tx.effective_gas_price(base_fee) };
+        self
+    }
+
     /// Applies deposit transaction metadata: deposit nonce.
     pub const fn deposit_nonce(mut self, nonce: Option<u64>) -> Self {
         self.deposit_nonce = nonce;
@@ -297,4 +418,45 @@ mod test {
             "incorrect l1 blob base fee scalar"
         );
     }
+
+    #[test]
+    fn effective_gas_price_deposit_tx_is_zero() {
+        // rig: the L1-attributes deposit transaction at index 0 of block 124665056
+        let tx_0 = TransactionSigned::decode_enveloped(
+            &mut TX_SET_L1_BLOCK_OP_MAINNET_BLOCK_124665056.as_slice(),
+        )
+        .unwrap();
+        assert!(tx_0.is_deposit());
+
+        // test: a deposit transaction pays no L1/L2 execution gas price, regardless of the L2
+        // base fee
+        let builder = OpReceiptFieldsBuilder::new(BLOCK_124665056_TIMESTAMP)
+            .effective_gas_price(&tx_0, Some(52_523_028));
+
+        assert_eq!(builder.effective_gas_price, 0, "deposit tx must report effectiveGasPrice 0");
+    }
+
+    #[test]
+    fn effective_gas_price_1559_tx_is_capped_at_max_fee() {
+        // rig: the EIP-1559 transaction at index 1 of block 124665056
+        let tx_1 =
+            TransactionSigned::decode_enveloped(&mut TX_1_OP_MAINNET_BLOCK_124665056.as_slice())
+                .unwrap();
+        assert!(!tx_1.is_deposit());
+
+        let base_fee = 52_523_028u64;
+        let expected = std::cmp::min(
+            tx_1.max_fee_per_gas(),
+            base_fee as u128 + tx_1.max_priority_fee_per_gas().unwrap_or_default(),
+        );
+
+        // test
+        let builder = OpReceiptFieldsBuilder::new(BLOCK_124665056_TIMESTAMP)
+            .effective_gas_price(&tx_1, Some(base_fee));
+
+        assert_eq!(
+            builder.effective_gas_price, expected,
+            "incorrect effective gas price for 1559 tx"
+        );
+    }
 }